@@ -1,11 +1,20 @@
 use anyhow::Result;
+#[cfg(feature = "render")]
+use fantoccini::{ClientBuilder as WebDriverClientBuilder, Locator};
 use futures::future::join_all;
+use futures::StreamExt;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
 use reqwest::{Client, ClientBuilder, StatusCode};
 use scraper::{Html, Selector};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
 use tokio::sync::Semaphore;
+use url::Url;
 
 #[derive(Debug)]
 #[pyclass]
@@ -37,44 +46,407 @@ impl From<std::string::FromUtf8Error> for ScrapingError {
     }
 }
 
+/// Pull the `charset` parameter out of a `Content-Type` header, defaulting to UTF-8
+fn charset_from_content_type(content_type: Option<&str>) -> String {
+    content_type
+        .and_then(|value| {
+            value
+                .split(';')
+                .skip(1)
+                .find_map(|param| param.trim().strip_prefix("charset="))
+        })
+        .unwrap_or("utf-8")
+        .trim_matches('"')
+        .to_ascii_lowercase()
+}
+
+/// Decode a Windows-1252 byte, the only range where it diverges from Latin-1
+fn decode_windows_1252_byte(byte: u8) -> char {
+    match byte {
+        0x80 => '\u{20AC}',
+        0x82 => '\u{201A}',
+        0x83 => '\u{0192}',
+        0x84 => '\u{201E}',
+        0x85 => '\u{2026}',
+        0x86 => '\u{2020}',
+        0x87 => '\u{2021}',
+        0x88 => '\u{02C6}',
+        0x89 => '\u{2030}',
+        0x8A => '\u{0160}',
+        0x8B => '\u{2039}',
+        0x8C => '\u{0152}',
+        0x8E => '\u{017D}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201C}',
+        0x94 => '\u{201D}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x98 => '\u{02DC}',
+        0x99 => '\u{2122}',
+        0x9A => '\u{0161}',
+        0x9B => '\u{203A}',
+        0x9C => '\u{0153}',
+        0x9E => '\u{017E}',
+        0x9F => '\u{0178}',
+        other => other as char,
+    }
+}
+
+/// Decode `body` per the response's declared charset: Latin-1 and Windows-1252 are
+/// decoded byte by byte, and any other charset is decoded as UTF-8
+fn decode_body(body: Vec<u8>, charset: &str) -> Result<String, ScrapingError> {
+    match charset {
+        "iso-8859-1" | "latin1" => Ok(body.into_iter().map(|b| b as char).collect()),
+        "windows-1252" | "cp1252" => Ok(body.into_iter().map(decode_windows_1252_byte).collect()),
+        _ => Ok(String::from_utf8(body)?),
+    }
+}
+
+/// Read a response body, aborting once it exceeds `max_response_bytes`
+async fn read_body_capped(
+    response: reqwest::Response,
+    max_response_bytes: Option<u64>,
+) -> Result<String, ScrapingError> {
+    let max_response_bytes = match max_response_bytes {
+        Some(limit) => limit,
+        None => return Ok(response.text().await?),
+    };
+
+    let charset = charset_from_content_type(
+        response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+    );
+
+    let mut body = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        body.extend_from_slice(&chunk);
+        if body.len() as u64 > max_response_bytes {
+            return Err(ScrapingError {
+                message: format!(
+                    "response exceeded max_response_bytes limit of {} bytes",
+                    max_response_bytes
+                ),
+            });
+        }
+    }
+
+    decode_body(body, &charset)
+}
+
+/// Fetch a URL with the same retry/backoff behavior as `FastScraper::fetch`
+async fn fetch_with_retries(
+    client: &Client,
+    url: &str,
+    max_retries: u32,
+    user_agent: Option<&str>,
+    max_response_bytes: Option<u64>,
+) -> Result<String, ScrapingError> {
+    let mut retries = 0;
+
+    loop {
+        let mut request = client.get(url);
+        if let Some(user_agent) = user_agent {
+            request = request.header(USER_AGENT, user_agent);
+        }
+
+        let result = match request.send().await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    read_body_capped(response, max_response_bytes).await
+                } else {
+                    Err(ScrapingError {
+                        message: format!("HTTP error: {}", status),
+                    })
+                }
+            }
+            Err(e) => Err(ScrapingError {
+                message: e.to_string(),
+            }),
+        };
+
+        match result {
+            Ok(content) => return Ok(content),
+            Err(e) => {
+                retries += 1;
+                if retries >= max_retries {
+                    return Err(e);
+                }
+                tokio::time::sleep(Duration::from_millis(1000 * retries as u64)).await;
+            }
+        }
+    }
+}
+
+/// Pick the next user agent from `user_agents` in round-robin order
+fn rotate_user_agent(user_agents: &[String], ua_index: &AtomicUsize) -> Option<String> {
+    if user_agents.is_empty() {
+        return None;
+    }
+    let i = ua_index.fetch_add(1, Ordering::Relaxed) % user_agents.len();
+    Some(user_agents[i].clone())
+}
+
+/// Normalize a crawled URL into a dedup key: drop the fragment and any trailing slash
+fn normalize_url(url: &Url) -> String {
+    let mut normalized = url.clone();
+    normalized.set_fragment(None);
+    let path = normalized.path().to_string();
+    if path.len() > 1 && path.ends_with('/') {
+        normalized.set_path(path.trim_end_matches('/'));
+    }
+    normalized.to_string()
+}
+
+/// Resolve and filter the `a[href]` links in `html` relative to `base`
+fn extract_links(html: &str, base: &Url, same_domain_only: bool, start_host: Option<&str>) -> Vec<Url> {
+    let document = Html::parse_document(html);
+    let link_selector = Selector::parse("a[href]").unwrap();
+    document
+        .select(&link_selector)
+        .filter_map(|el| el.value().attr("href"))
+        .filter_map(|href| base.join(href).ok())
+        .filter(|link| !same_domain_only || link.host_str() == start_host)
+        .collect()
+}
+
+/// A `select_all` field: a CSS selector, or a `(selector, attribute)` pair
+#[derive(Clone)]
+enum FieldSpec {
+    Text(String),
+    Attr(String, String),
+}
+
+impl FieldSpec {
+    fn selector(&self) -> &str {
+        match self {
+            FieldSpec::Text(selector) => selector,
+            FieldSpec::Attr(selector, _) => selector,
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for FieldSpec {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok((selector, attr)) = ob.extract::<(String, String)>() {
+            return Ok(FieldSpec::Attr(selector, attr));
+        }
+        if let Ok(selector) = ob.extract::<String>() {
+            return Ok(FieldSpec::Text(selector));
+        }
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "field spec must be a CSS selector string or a (selector, attribute) tuple",
+        ))
+    }
+}
+
+/// Extract one record per element matching `row_selector`, field by field
+fn build_records(
+    document: &Html,
+    row_selector: &Selector,
+    fields: &[(String, Selector, FieldSpec)],
+) -> Vec<HashMap<String, Option<String>>> {
+    document
+        .select(row_selector)
+        .map(|row| {
+            fields
+                .iter()
+                .map(|(name, selector, field_spec)| {
+                    let value = row.select(selector).next().and_then(|element| {
+                        match field_spec {
+                            FieldSpec::Text(_) => {
+                                Some(element.text().collect::<Vec<_>>().join(""))
+                            }
+                            FieldSpec::Attr(_, attr) => {
+                                element.value().attr(attr).map(String::from)
+                            }
+                        }
+                    });
+                    (name.clone(), value)
+                })
+                .collect::<HashMap<_, _>>()
+        })
+        .collect()
+}
+
+/// The subset of `FastScraper::new`'s client-building params needed to rebuild
+/// the `reqwest::Client` when a builder-style `with_*` setter changes one of them
+#[derive(Clone)]
+struct ClientConfig {
+    timeout_ms: u64,
+    headers: HashMap<String, String>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+    max_redirects: usize,
+    follow_redirects: bool,
+}
+
+/// Build a `reqwest::Client` from a `ClientConfig`
+fn build_client(config: &ClientConfig) -> PyResult<Client> {
+    let redirect_policy = if config.follow_redirects {
+        reqwest::redirect::Policy::limited(config.max_redirects)
+    } else {
+        reqwest::redirect::Policy::none()
+    };
+
+    let mut builder = ClientBuilder::new()
+        .timeout(Duration::from_millis(config.timeout_ms))
+        .redirect(redirect_policy);
+
+    if !config.headers.is_empty() {
+        let mut header_map = HeaderMap::new();
+        for (key, value) in &config.headers {
+            let name = HeaderName::from_bytes(key.as_bytes())
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+            header_map.insert(name, value);
+        }
+        builder = builder.default_headers(header_map);
+    }
+
+    if let Some(user_agent) = &config.user_agent {
+        builder = builder.user_agent(user_agent.clone());
+    }
+
+    if let Some(proxy) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        builder = builder.proxy(proxy);
+    }
+
+    builder
+        .build()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+}
+
 /// A fast web scraper implemented in Rust
 #[pyclass]
 struct FastScraper {
     client: Client,
+    config: ClientConfig,
     max_retries: u32,
     rate_limit: Option<Arc<Semaphore>>,
+    user_agents: Arc<Vec<String>>,
+    ua_index: Arc<AtomicUsize>,
+    runtime: Arc<tokio::runtime::Runtime>,
+    max_response_bytes: Option<u64>,
+    webdriver_url: Option<String>,
+}
+
+impl FastScraper {
+    /// Rebuild the client from `config` and return a new `FastScraper` that shares
+    /// this one's runtime, rate limiter, and user-agent rotation state
+    fn with_config(&self, config: ClientConfig) -> PyResult<Self> {
+        let client = build_client(&config)?;
+        Ok(FastScraper {
+            client,
+            config,
+            max_retries: self.max_retries,
+            rate_limit: self.rate_limit.clone(),
+            user_agents: self.user_agents.clone(),
+            ua_index: self.ua_index.clone(),
+            runtime: self.runtime.clone(),
+            max_response_bytes: self.max_response_bytes,
+            webdriver_url: self.webdriver_url.clone(),
+        })
+    }
 }
 
 #[pymethods]
 impl FastScraper {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         timeout_ms=5000,
         max_retries=3,
-        max_concurrent_requests=None
+        max_concurrent_requests=None,
+        headers=None,
+        user_agent=None,
+        proxy=None,
+        user_agents=None,
+        max_response_bytes=None,
+        max_redirects=5,
+        follow_redirects=true,
+        webdriver_url=None
     ))]
     fn new(
         timeout_ms: u64,
         max_retries: u32,
         max_concurrent_requests: Option<usize>,
+        headers: Option<HashMap<String, String>>,
+        user_agent: Option<String>,
+        proxy: Option<String>,
+        user_agents: Option<Vec<String>>,
+        max_response_bytes: Option<u64>,
+        max_redirects: usize,
+        follow_redirects: bool,
+        webdriver_url: Option<String>,
     ) -> PyResult<Self> {
-        let client = ClientBuilder::new()
-            .timeout(Duration::from_millis(timeout_ms))
-            .build()
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+        if max_concurrent_requests == Some(0) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "max_concurrent_requests must be greater than 0",
+            ));
+        }
+
+        let config = ClientConfig {
+            timeout_ms,
+            headers: headers.unwrap_or_default(),
+            user_agent,
+            proxy,
+            max_redirects,
+            follow_redirects,
+        };
+        let client = build_client(&config)?;
 
         let rate_limit = max_concurrent_requests.map(|n| Arc::new(Semaphore::new(n)));
 
+        let runtime = tokio::runtime::Runtime::new()
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
         Ok(FastScraper {
             client,
+            config,
             max_retries,
             rate_limit,
+            user_agents: Arc::new(user_agents.unwrap_or_default()),
+            ua_index: Arc::new(AtomicUsize::new(0)),
+            runtime: Arc::new(runtime),
+            max_response_bytes,
+            webdriver_url,
         })
     }
 
+    /// Return a new `FastScraper` with `value` added to its default request headers
+    fn with_header(&self, key: String, value: String) -> PyResult<Self> {
+        let mut config = self.config.clone();
+        config.headers.insert(key, value);
+        self.with_config(config)
+    }
+
+    /// Return a new `FastScraper` that sends `user_agent` as its default User-Agent
+    fn with_user_agent(&self, user_agent: String) -> PyResult<Self> {
+        let mut config = self.config.clone();
+        config.user_agent = Some(user_agent);
+        self.with_config(config)
+    }
+
+    /// Return a new `FastScraper` that routes requests through `proxy`
+    fn with_proxy(&self, proxy: String) -> PyResult<Self> {
+        let mut config = self.config.clone();
+        config.proxy = Some(proxy);
+        self.with_config(config)
+    }
+
     /// Fetch a URL and return the HTML content with retry mechanism
     fn fetch(&self, url: &str) -> PyResult<String> {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let runtime = self.runtime.clone();
         let mut retries = 0;
 
         while retries < self.max_retries {
@@ -83,15 +455,16 @@ impl FastScraper {
                     let _permit = rate_limit.acquire().await.unwrap();
                 }
 
-                match self.client.get(url).send().await {
+                let mut request = self.client.get(url);
+                if let Some(user_agent) = rotate_user_agent(&self.user_agents, &self.ua_index) {
+                    request = request.header(USER_AGENT, user_agent);
+                }
+
+                match request.send().await {
                     Ok(response) => {
                         let status = response.status();
                         if status.is_success() {
-                            Ok(response.text().await?)
-                        } else if status.is_server_error() && retries < self.max_retries - 1 {
-                            Err(ScrapingError {
-                                message: format!("HTTP error: {}", status),
-                            })
+                            read_body_capped(response, self.max_response_bytes).await
                         } else {
                             Err(ScrapingError {
                                 message: format!("HTTP error: {}", status),
@@ -122,24 +495,33 @@ impl FastScraper {
 
     /// Fetch multiple URLs concurrently
     fn fetch_many(&self, urls: Vec<String>) -> PyResult<Vec<String>> {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let runtime = self.runtime.clone();
         let client = self.client.clone();
         let rate_limit = self.rate_limit.clone();
+        let user_agents = self.user_agents.clone();
+        let ua_index = self.ua_index.clone();
+        let max_response_bytes = self.max_response_bytes;
 
         let results = runtime.block_on(async {
             let mut futures = Vec::new();
             for url in urls {
                 let client = &client;
                 let rate_limit = rate_limit.clone();
+                let user_agent = rotate_user_agent(&user_agents, &ua_index);
 
                 let future = async move {
                     if let Some(rate_limit) = rate_limit {
                         let _permit = rate_limit.acquire().await.unwrap();
                     }
 
-                    match client.get(&url).send().await {
+                    let mut request = client.get(&url);
+                    if let Some(user_agent) = user_agent {
+                        request = request.header(USER_AGENT, user_agent);
+                    }
+
+                    match request.send().await {
                         Ok(response) => match response.status() {
-                            StatusCode::OK => Ok(response.text().await?),
+                            StatusCode::OK => read_body_capped(response, max_response_bytes).await,
                             status => Err(ScrapingError {
                                 message: format!("HTTP error: {}", status),
                             }),
@@ -161,6 +543,146 @@ impl FastScraper {
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
     }
 
+    /// Breadth-first crawl from `start_url`, returning a dict of URL to HTML
+    #[pyo3(signature = (
+        start_url,
+        max_depth=2,
+        max_pages=50,
+        same_domain_only=true
+    ))]
+    fn crawl(
+        &self,
+        start_url: &str,
+        max_depth: usize,
+        max_pages: usize,
+        same_domain_only: bool,
+    ) -> PyResult<PyObject> {
+        let runtime = self.runtime.clone();
+        let client = self.client.clone();
+        let rate_limit = self.rate_limit.clone();
+        let max_retries = self.max_retries;
+        let user_agents = self.user_agents.clone();
+        let ua_index = self.ua_index.clone();
+        let max_response_bytes = self.max_response_bytes;
+
+        let start = Url::parse(start_url)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        let start_host = start.host_str().map(String::from);
+
+        let pages = runtime.block_on(async move {
+            let frontier = Arc::new(Mutex::new(VecDeque::from([(start.clone(), 0usize)])));
+            let visited = Arc::new(Mutex::new(HashSet::from([normalize_url(&start)])));
+            let pages = Arc::new(Mutex::new(Vec::<(String, String)>::new()));
+            // Tracks workers currently between popping a URL and pushing its links, so an
+            // idle worker can tell "frontier is empty because we're done" apart from
+            // "frontier is empty but another worker is about to refill it".
+            let in_flight = Arc::new(AtomicUsize::new(0));
+
+            let worker_count = rate_limit
+                .as_ref()
+                .map(|s| s.available_permits())
+                .unwrap_or(4)
+                .max(1);
+
+            let mut workers = Vec::with_capacity(worker_count);
+            for _ in 0..worker_count {
+                let frontier = frontier.clone();
+                let visited = visited.clone();
+                let pages = pages.clone();
+                let client = client.clone();
+                let rate_limit = rate_limit.clone();
+                let start_host = start_host.clone();
+                let user_agents = user_agents.clone();
+                let ua_index = ua_index.clone();
+                let in_flight = in_flight.clone();
+
+                workers.push(tokio::spawn(async move {
+                    loop {
+                        if pages.lock().await.len() >= max_pages {
+                            break;
+                        }
+
+                        let next = {
+                            let mut frontier = frontier.lock().await;
+                            match frontier.pop_front() {
+                                Some(item) => {
+                                    in_flight.fetch_add(1, Ordering::SeqCst);
+                                    Some(item)
+                                }
+                                None if in_flight.load(Ordering::SeqCst) == 0 => break,
+                                None => None,
+                            }
+                        };
+                        let (url, depth) = match next {
+                            Some(item) => item,
+                            None => {
+                                tokio::time::sleep(Duration::from_millis(20)).await;
+                                continue;
+                            }
+                        };
+
+                        if let Some(rate_limit) = &rate_limit {
+                            let _permit = rate_limit.acquire().await.unwrap();
+                        }
+
+                        let user_agent = rotate_user_agent(&user_agents, &ua_index);
+                        let body = fetch_with_retries(
+                            &client,
+                            url.as_str(),
+                            max_retries,
+                            user_agent.as_deref(),
+                            max_response_bytes,
+                        )
+                        .await;
+
+                        let body = match body {
+                            Ok(body) => body,
+                            Err(_) => {
+                                in_flight.fetch_sub(1, Ordering::SeqCst);
+                                continue;
+                            }
+                        };
+
+                        {
+                            let mut pages = pages.lock().await;
+                            if pages.len() >= max_pages {
+                                in_flight.fetch_sub(1, Ordering::SeqCst);
+                                break;
+                            }
+                            pages.push((url.as_str().to_string(), body.clone()));
+                        }
+
+                        if depth < max_depth {
+                            let links =
+                                extract_links(&body, &url, same_domain_only, start_host.as_deref());
+
+                            let mut frontier = frontier.lock().await;
+                            let mut visited = visited.lock().await;
+                            for link in links {
+                                if visited.insert(normalize_url(&link)) {
+                                    frontier.push_back((link, depth + 1));
+                                }
+                            }
+                        }
+
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    }
+                }));
+            }
+
+            join_all(workers).await;
+            Arc::try_unwrap(pages).unwrap_or_else(|_| unreachable!()).into_inner()
+        });
+
+        Python::with_gil(|py| {
+            let dict = PyDict::new(py);
+            for (url, html) in pages {
+                dict.set_item(url, html)?;
+            }
+            Ok(dict.into())
+        })
+    }
+
     /// Extract elements using CSS selector
     fn select(&self, html: &str, selector: &str) -> PyResult<Vec<String>> {
         let document = Html::parse_document(html);
@@ -203,21 +725,55 @@ impl FastScraper {
         Ok(elements)
     }
 
+    /// Extract a dict of fields per row matching `row_selector`
+    fn select_all(
+        &self,
+        html: &str,
+        row_selector: &str,
+        spec: HashMap<String, FieldSpec>,
+    ) -> PyResult<Vec<HashMap<String, Option<String>>>> {
+        let document = Html::parse_document(html);
+        let row_selector = Selector::parse(row_selector).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid selector: {}", e))
+        })?;
+
+        let mut fields = Vec::with_capacity(spec.len());
+        for (name, field_spec) in spec {
+            let selector = Selector::parse(field_spec.selector()).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Invalid selector: {}",
+                    e
+                ))
+            })?;
+            fields.push((name, selector, field_spec));
+        }
+
+        Ok(build_records(&document, &row_selector, &fields))
+    }
+
     /// Fetch and parse JSON from a URL
     fn fetch_json(&self, url: &str) -> PyResult<PyObject> {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let runtime = self.runtime.clone();
         let response = runtime.block_on(async {
             if let Some(rate_limit) = &self.rate_limit {
                 let _permit = rate_limit.acquire().await.unwrap();
             }
 
-            self.client
-                .get(url)
+            let mut request = self.client.get(url);
+            if let Some(user_agent) = rotate_user_agent(&self.user_agents, &self.ua_index) {
+                request = request.header(USER_AGENT, user_agent);
+            }
+
+            let response = request
                 .send()
                 .await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
-                .json::<serde_json::Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+            let body = read_body_capped(response, self.max_response_bytes)
                 .await
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+            serde_json::from_str::<serde_json::Value>(&body)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
         })?;
 
@@ -228,6 +784,53 @@ impl FastScraper {
             Ok(json_dict.into())
         })
     }
+
+    /// Drive a WebDriver session to load `url` and return the fully rendered DOM HTML
+    #[cfg(feature = "render")]
+    #[pyo3(signature = (url, wait_for_selector=None))]
+    fn render(&self, url: &str, wait_for_selector: Option<&str>) -> PyResult<String> {
+        let webdriver_url = self.webdriver_url.as_deref().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                "render() requires a webdriver_url; start a WebDriver server (e.g. chromedriver \
+                 or geckodriver) and pass its URL to FastScraper::new",
+            )
+        })?;
+
+        self.runtime.block_on(async {
+            let client = WebDriverClientBuilder::native()
+                .connect(webdriver_url)
+                .await
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+            let result = async {
+                client.goto(url).await?;
+                if let Some(selector) = wait_for_selector {
+                    client.wait().for_element(Locator::Css(selector)).await?;
+                }
+                client.source().await
+            }
+            .await;
+
+            client
+                .close()
+                .await
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+            result.map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+        })
+    }
+
+    /// Without the `render` feature, always raise a `RuntimeError` saying the crate was
+    /// built without WebDriver-backed rendering
+    #[cfg(not(feature = "render"))]
+    #[pyo3(signature = (url, wait_for_selector=None))]
+    fn render(&self, url: &str, wait_for_selector: Option<&str>) -> PyResult<String> {
+        let _ = (url, wait_for_selector, &self.webdriver_url);
+        Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            "render() requires fast_scraping_rs to be built with the `render` feature enabled \
+             (WebDriver-backed rendering via fantoccini)",
+        ))
+    }
 }
 
 /// A Python module implemented in Rust.
@@ -236,3 +839,196 @@ fn rust(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<FastScraper>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charset_from_content_type_extracts_charset_param() {
+        assert_eq!(
+            charset_from_content_type(Some("text/html; charset=ISO-8859-1")),
+            "iso-8859-1"
+        );
+        assert_eq!(
+            charset_from_content_type(Some("text/html; charset=\"windows-1252\"")),
+            "windows-1252"
+        );
+    }
+
+    #[test]
+    fn charset_from_content_type_defaults_to_utf8() {
+        assert_eq!(charset_from_content_type(Some("text/html")), "utf-8");
+        assert_eq!(charset_from_content_type(None), "utf-8");
+    }
+
+    #[test]
+    fn decode_windows_1252_byte_maps_high_range_and_passes_through_rest() {
+        assert_eq!(decode_windows_1252_byte(0x80), '\u{20AC}');
+        assert_eq!(decode_windows_1252_byte(0x92), '\u{2019}');
+        assert_eq!(decode_windows_1252_byte(b'A'), 'A');
+    }
+
+    #[test]
+    fn decode_body_decodes_latin1_and_windows_1252() {
+        // 0xE9 is Latin-1/Windows-1252 for U+00E9 (e-acute)
+        let body = vec![b'c', b'a', b'f', 0xE9];
+        assert_eq!(decode_body(body.clone(), "iso-8859-1").unwrap(), "café");
+        assert_eq!(decode_body(body, "windows-1252").unwrap(), "café");
+    }
+
+    #[test]
+    fn decode_body_decodes_utf8_by_default() {
+        let body = "café".as_bytes().to_vec();
+        assert_eq!(decode_body(body, "utf-8").unwrap(), "café");
+    }
+
+    #[test]
+    fn decode_body_errors_on_invalid_utf8() {
+        let body = vec![0xFF, 0xFE];
+        assert!(decode_body(body, "utf-8").is_err());
+    }
+
+    #[test]
+    fn normalize_url_ignores_fragment_and_trailing_slash() {
+        let with_fragment = Url::parse("https://example.com/page#section").unwrap();
+        let with_slash = Url::parse("https://example.com/page/").unwrap();
+        let bare = Url::parse("https://example.com/page").unwrap();
+
+        assert_eq!(normalize_url(&with_fragment), normalize_url(&bare));
+        assert_eq!(normalize_url(&with_slash), normalize_url(&bare));
+    }
+
+    #[test]
+    fn normalize_url_keeps_root_slash() {
+        let url = Url::parse("https://example.com/").unwrap();
+        assert_eq!(normalize_url(&url), "https://example.com/");
+    }
+
+    #[test]
+    fn extract_links_resolves_relative_hrefs() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<a href="post-1">Post</a>"#;
+        let links = extract_links(html, &base, false, None);
+
+        assert_eq!(links, vec![Url::parse("https://example.com/blog/post-1").unwrap()]);
+    }
+
+    #[test]
+    fn extract_links_drops_other_domains_when_restricted() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<a href="/a">same</a><a href="https://other.com/b">other</a>"#;
+        let links = extract_links(html, &base, true, base.host_str());
+
+        assert_eq!(links, vec![Url::parse("https://example.com/a").unwrap()]);
+    }
+
+    #[test]
+    fn extract_links_keeps_other_domains_when_unrestricted() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<a href="https://other.com/b">other</a>"#;
+        let links = extract_links(html, &base, false, base.host_str());
+
+        assert_eq!(links, vec![Url::parse("https://other.com/b").unwrap()]);
+    }
+
+    #[test]
+    fn build_records_extracts_text_and_attr_fields() {
+        let html = r#"
+            <div class="row"><span class="name">Widget</span><a class="link" href="/w">link</a></div>
+        "#;
+        let document = Html::parse_document(html);
+        let row_selector = Selector::parse(".row").unwrap();
+        let fields = vec![
+            (
+                "name".to_string(),
+                Selector::parse(".name").unwrap(),
+                FieldSpec::Text(".name".to_string()),
+            ),
+            (
+                "href".to_string(),
+                Selector::parse(".link").unwrap(),
+                FieldSpec::Attr(".link".to_string(), "href".to_string()),
+            ),
+        ];
+
+        let records = build_records(&document, &row_selector, &fields);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("name"), Some(&Some("Widget".to_string())));
+        assert_eq!(records[0].get("href"), Some(&Some("/w".to_string())));
+    }
+
+    #[test]
+    fn build_records_fills_missing_field_with_none() {
+        let html = r#"<div class="row"><span class="name">Widget</span></div>"#;
+        let document = Html::parse_document(html);
+        let row_selector = Selector::parse(".row").unwrap();
+        let fields = vec![
+            (
+                "name".to_string(),
+                Selector::parse(".name").unwrap(),
+                FieldSpec::Text(".name".to_string()),
+            ),
+            (
+                "price".to_string(),
+                Selector::parse(".price").unwrap(),
+                FieldSpec::Text(".price".to_string()),
+            ),
+        ];
+
+        let records = build_records(&document, &row_selector, &fields);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("name"), Some(&Some("Widget".to_string())));
+        assert_eq!(records[0].get("price"), Some(&None));
+    }
+
+    #[test]
+    fn new_rejects_zero_max_concurrent_requests() {
+        let result = FastScraper::new(
+            5000,
+            3,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            5,
+            true,
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn with_header_adds_header_without_mutating_original() {
+        let scraper =
+            FastScraper::new(5000, 3, None, None, None, None, None, None, 5, true, None).unwrap();
+        let with_header = scraper
+            .with_header("X-Api-Key".to_string(), "secret".to_string())
+            .unwrap();
+
+        assert!(scraper.config.headers.is_empty());
+        assert_eq!(
+            with_header.config.headers.get("X-Api-Key"),
+            Some(&"secret".to_string())
+        );
+    }
+
+    #[test]
+    fn with_user_agent_and_with_proxy_update_config() {
+        let scraper =
+            FastScraper::new(5000, 3, None, None, None, None, None, None, 5, true, None).unwrap();
+
+        let with_ua = scraper.with_user_agent("test-agent/1.0".to_string()).unwrap();
+        assert_eq!(with_ua.config.user_agent, Some("test-agent/1.0".to_string()));
+
+        let with_proxy = scraper.with_proxy("http://localhost:8080".to_string()).unwrap();
+        assert_eq!(
+            with_proxy.config.proxy,
+            Some("http://localhost:8080".to_string())
+        );
+    }
+}